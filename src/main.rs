@@ -1,11 +1,18 @@
+use std::iter::FromIterator;
 use std::sync::Arc;
 
 use arrow::array::{
-    Array, ArrayData, BooleanArray, Int32Array, Int32Builder, ListArray, PrimitiveArray,
-    StringArray, StructArray, StringBuilder, ArrayBuilder, StructBuilder
+    Array, ArrayData, BooleanArray, DictionaryArray, Int32Array, Int32Builder,
+    LargeListArray, LargeStringArray, ListArray,
+    MapBuilder, PrimitiveArray, PrimitiveBuilder, PrimitiveDictionaryBuilder, StringArray,
+    ArrayRef, FixedSizeBinaryArray, FixedSizeListBuilder, StringDictionaryBuilder, StructArray,
+    StringBuilder, ArrayBuilder, StructBuilder, UnionBuilder
 };
 use arrow::buffer::Buffer;
-use arrow::datatypes::{DataType, Date64Type, Field, Time64MicrosecondType, ToByteSlice};
+use arrow::datatypes::{
+    ArrowDictionaryKeyType, DataType, Date64Type, Field, Float64Type, Int8Type, Int32Type,
+    Time64MicrosecondType, ToByteSlice,
+};
 
 fn main() {
     // Primitive types
@@ -224,4 +231,310 @@ fn main() {
     let struct_array = StructArray::from(struct_array_data);
 
     println!("{:?}", struct_array);
+
+    // DictionaryArray
+    //
+    // Nested arrays are not the only way the Arrow specification compresses data. Columns with a
+    // low cardinality -- that is, columns where a small set of values is repeated many times, such
+    // as a list of colors or country codes -- can be dictionary encoded. Instead of storing every
+    // repeated value, a DictionaryArray stores each distinct value once in a "values" (or
+    // dictionary) array and represents the column as a buffer of integer "keys" that index into
+    // that dictionary. This trades a handful of bytes per row for a single small integer, which is
+    // why it is such a natural fit for low-cardinality columns.
+    //
+    // The dictionary builders take care of the interning for us: every time a value is appended the
+    // builder checks whether it already exists in the dictionary and, if so, reuses its key instead
+    // of storing the value again. The build_string_dictionary helper below wraps a
+    // StringDictionaryBuilder and appends the repeated colors, auto-interning them as it goes.
+    let colors = ["red", "red", "green", "blue", "green"];
+    let color_dict = build_string_dictionary::<Int8Type>(&colors);
+    println!("{:?}", color_dict);
+
+    // By printing the keys and the dictionary values separately the compression becomes visible:
+    // the five appended colors collapse into three distinct dictionary entries, and the keys buffer
+    // holds the integer indices (0, 0, 1, 2, 1) that point back into them.
+    println!("{:?}", color_dict.keys());
+    println!("{:?}", color_dict.values());
+
+    // The same mechanism works for primitive values through the PrimitiveDictionaryBuilder<K, V>,
+    // which is parameterized over both the key type and the value type. Here Int32 values are
+    // interned behind Int8 keys in exactly the same way the strings were above.
+    let keys_builder = PrimitiveBuilder::<Int8Type>::new(5);
+    let values_builder = PrimitiveBuilder::<Int32Type>::new(5);
+    let mut primitive_dict_builder =
+        PrimitiveDictionaryBuilder::new(keys_builder, values_builder);
+    primitive_dict_builder.append(10).unwrap();
+    primitive_dict_builder.append(10).unwrap();
+    primitive_dict_builder.append(20).unwrap();
+    primitive_dict_builder.append(30).unwrap();
+    primitive_dict_builder.append(20).unwrap();
+    let primitive_dict = primitive_dict_builder.finish();
+    println!("{:?}", primitive_dict);
+    println!("{:?}", primitive_dict.keys());
+    println!("{:?}", primitive_dict.values());
+
+    // MapArray
+    //
+    // A MapArray represents a column of maps -- each row is a set of key/value entries, like a
+    // small dictionary per row. Mechanically it is just a ListArray whose child is a
+    // non-nullable struct of {key, value}: the list offsets mark where each row's entries start
+    // and end, while the two struct children hold the flattened keys and values of every row.
+    //
+    // The MapBuilder wires this together for us. It wraps a key builder and a value builder --
+    // here a StringBuilder for the keys and an Int32Builder for the values -- and exposes them
+    // through keys() and values(). Entries are appended to those child builders and then a call to
+    // append(true) closes the current map (append(false) would record a null map instead).
+    let string_builder = StringBuilder::new(8);
+    let int_builder = Int32Builder::new(8);
+    let mut map_builder = MapBuilder::new(None, string_builder, int_builder);
+
+    // First map: {"joe" => 1}
+    map_builder.keys().append_value("joe").unwrap();
+    map_builder.values().append_value(1).unwrap();
+    map_builder.append(true).unwrap();
+
+    // Second map: {"blogs" => 2, "foo" => 4}
+    map_builder.keys().append_value("blogs").unwrap();
+    map_builder.values().append_value(2).unwrap();
+    map_builder.keys().append_value("foo").unwrap();
+    map_builder.values().append_value(4).unwrap();
+    map_builder.append(true).unwrap();
+
+    // Third entry: a null map. No keys or values are appended before closing it with append(false).
+    map_builder.append(false).unwrap();
+
+    // Fourth map: an empty map {} -- a valid entry with no key/value pairs.
+    map_builder.append(true).unwrap();
+
+    let map_array = map_builder.finish();
+    println!("{:?}", map_array);
+
+    // Printing the pieces makes the "list of structs" layout explicit: the offsets buffer marks the
+    // [start, end) span of each row inside the flattened children, while the key and value child
+    // arrays hold every entry's key and value back to back across all the rows.
+    println!("{:?}", map_array.value_offsets());
+    println!("{:?}", map_array.keys());
+    println!("{:?}", map_array.values());
+
+    // UnionArray
+    //
+    // A UnionArray stores values that may be of more than one type in a single column. Each slot is
+    // tagged with a "type id" that says which child array -- and therefore which type -- the value
+    // belongs to. The Arrow specification defines two physical encodings for this, sparse and
+    // dense, both of which the UnionBuilder can produce.
+    //
+    // In the sparse encoding every child array has the full length of the union: for each slot all
+    // children hold a value, but only the child selected by that slot's type id is meaningful (the
+    // others are padding). This wastes space but needs no offsets buffer, so random access is just
+    // an index into the selected child.
+    let mut sparse_builder = UnionBuilder::new_sparse(3);
+    sparse_builder.append::<Int32Type>("a", 1).unwrap();
+    sparse_builder.append::<Float64Type>("b", 3.0).unwrap();
+    sparse_builder.append::<Int32Type>("a", 4).unwrap();
+    let sparse_union = sparse_builder.build().unwrap();
+    println!("{:?}", sparse_union);
+
+    // The type-ids buffer (the first buffer of the array data) records, per slot, which child the
+    // value came from. The "a" and "b" passed to append are just the builder's field names; the
+    // buffer itself stores the integer type-id assigned to each field, so this prints [0, 1, 0]
+    // (field "a" is type-id 0, field "b" is type-id 1). A sparse union has no second buffer.
+    println!("{:?}", sparse_union.data().buffers()[0]);
+
+    // In the dense encoding each child only stores its own values, so the children are no longer
+    // the full length of the union. A second "offsets" buffer is therefore required: alongside the
+    // type id, each slot records the offset into the selected child where its value lives.
+    let mut dense_builder = UnionBuilder::new_dense(3);
+    dense_builder.append::<Int32Type>("a", 1).unwrap();
+    dense_builder.append::<Float64Type>("b", 3.0).unwrap();
+    dense_builder.append::<Int32Type>("a", 4).unwrap();
+    let dense_union = dense_builder.build().unwrap();
+    println!("{:?}", dense_union);
+
+    // Printing both buffers side by side shows the mechanical difference from the sparse variant:
+    // the type-ids buffer holds the same integer type-ids [0, 1, 0] as before, but the dense union
+    // additionally carries an offsets buffer of i32 offsets [0, 0, 1] pointing into the compacted
+    // children. Like any raw buffer it prints as bytes, so each offset shows up as 4 bytes:
+    // [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0].
+    println!("{:?}", dense_union.data().buffers()[0]);
+    println!("{:?}", dense_union.data().buffers()[1]);
+
+    // LargeListArray and LargeStringArray
+    //
+    // Both the StringArray and the ListArray built above address their child data with 32-bit
+    // offsets. That keeps the offset buffer small, but it caps the total amount of child data a
+    // single array can reference at i32::MAX -- roughly 2GB. When a column of strings or lists
+    // needs to hold more bytes than that, the "Large" variants use 64-bit offsets instead. The
+    // only structural difference is the offset buffer: it is built from a &[i64] slice rather than
+    // a &[i32] slice, so it can index far beyond the 2GB ceiling at the cost of twice the offset
+    // storage.
+    //
+    // Using the same logical data as the StringArray above -- ["hello", null, "parquet"] -- the
+    // only change is that the offsets are i64.
+    let large_string_offsets: [i64; 4] = [0, 5, 5, 12];
+    let large_string_data = ArrayData::builder(DataType::LargeUtf8)
+        .len(3)
+        .add_buffer(Buffer::from(large_string_offsets.to_byte_slice()))
+        .add_buffer(Buffer::from(&values[..]))
+        .null_bit_buffer(Buffer::from([0b00000101]))
+        .build();
+    let large_string_array = LargeStringArray::from(large_string_data);
+    println!("{:?}", large_string_array);
+
+    // Likewise the LargeListArray mirrors the ListArray [[0, 1, 2], [3, 4, 5], [6, 7]], building a
+    // child with the same values as its value_data but addressing it through i64 offsets.
+    let large_value_data = ArrayData::builder(DataType::Int32)
+        .len(8)
+        .add_buffer(Buffer::from(&[0, 1, 2, 3, 4, 5, 6, 7, 8].to_byte_slice()))
+        .build();
+
+    let large_value_offsets = Buffer::from(&[0i64, 3, 6, 8].to_byte_slice());
+
+    let large_list_data_type = DataType::LargeList(Box::new(DataType::Int32));
+    let large_list_data = ArrayData::builder(large_list_data_type)
+        .len(3)
+        .add_buffer(large_value_offsets)
+        .add_child_data(large_value_data)
+        .build();
+    let large_list_array = LargeListArray::from(large_list_data);
+    println!("{:?}", large_list_array);
+
+    // Printing the i32 and i64 offset buffers side by side shows that the two encodings carry the
+    // exact same sequence of boundaries; only the element width of the offset buffer differs.
+    println!("i32 string offsets: {:?}", offsets);
+    println!("i64 string offsets: {:?}", large_string_offsets);
+
+    // In-place mutation and copy-on-write buffers
+    //
+    // Every array built so far has been constructed, printed and then left alone. Arrow also
+    // supports transforming a PrimitiveArray, and when the underlying buffer is uniquely owned it
+    // can do so with zero allocation by mutating the existing buffer in place. unary_mut consumes
+    // the array and attempts exactly this: if the data buffer's reference count is 1 -- no other
+    // Array or Buffer points at the same allocation -- the closure is applied directly over the
+    // owned bytes and an Ok(array) is returned. This is only sound because nobody else can observe
+    // the buffer changing underneath them.
+    let owned = Int32Array::from(vec![5, 6, 7, 8]);
+    println!("before: {:?}", owned);
+    match owned.unary_mut(|x| x + 1) {
+        Ok(mutated) => {
+            // In-place path: the uniquely owned buffer was reused, no new allocation was made.
+            println!("in-place path taken (buffer reused)");
+            println!("after: {:?}", mutated);
+        }
+        Err(original) => {
+            // This branch is unreachable here because the array is uniquely owned, but it mirrors
+            // the fallback handled explicitly below for a shared array.
+            println!("unexpected: buffer was shared");
+            let fresh: Int32Array = original.unary(|x| x + 1);
+            println!("after: {:?}", fresh);
+        }
+    }
+
+    // The copy-on-write invariant is what makes the in-place path conditional. As soon as a second
+    // owner holds a reference to the same allocation -- for instance after cloning the array into
+    // an ArrayRef -- mutating in place would corrupt that other view. unary_mut detects the shared
+    // buffer (reference count > 1), declines to mutate, and hands the array back through Err so the
+    // caller can fall back to unary, which allocates a fresh buffer and leaves the shared one
+    // untouched.
+    let shared = Int32Array::from(vec![5, 6, 7, 8]);
+    let aliased: ArrayRef = Arc::new(shared.clone());
+    println!("before: {:?}", shared);
+    match shared.unary_mut(|x| x + 1) {
+        Ok(mutated) => {
+            println!("in-place path taken (buffer reused)");
+            println!("after: {:?}", mutated);
+        }
+        Err(original) => {
+            // Shared buffer path: a fresh allocation is produced so the aliased view keeps its
+            // original values.
+            println!("shared buffer, allocating fresh buffer");
+            let fresh: Int32Array = original.unary(|x| x + 1);
+            println!("after: {:?}", fresh);
+        }
+    }
+    // The aliased array still observes the untouched original allocation.
+    println!("{:?}", aliased);
+
+    // Ergonomic iterator constructors
+    //
+    // The arrays above were built either through the verbose ArrayData::builder plumbing or through
+    // basic Vec<Option<T>> conversions. For the common case there are higher-level constructors
+    // that build an array straight from an iterator, which is both shorter and clearer about
+    // intent.
+    //
+    // from_iter accepts an iterator of Option values, turning Nones into nulls, while
+    // from_iter_values takes plain values when there are no nulls to express.
+    let ergonomic_primitive = Int32Array::from_iter([Some(5), None, Some(2_000_000)]);
+    println!("{:?}", ergonomic_primitive);
+
+    let ergonomic_values = Int32Array::from_iter_values([1, 2, 3]);
+    println!("{:?}", ergonomic_values);
+
+    let ergonomic_strings = StringArray::from_iter_values(["hello", "parquet"]);
+    println!("{:?}", ergonomic_strings);
+
+    // The payoff is largest for nested arrays. from_iter_primitive builds a full ListArray -- child
+    // values, offsets and validity -- directly from an iterator of Option<Vec<Option<i32>>>, where
+    // the outer Option marks a null list, an inner None marks a null element, and an empty Vec
+    // marks an empty sub-list.
+    let ergonomic_list = ListArray::from_iter_primitive::<Int32Type, _, _>([
+        Some(vec![Some(0), Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(6), None]),
+    ]);
+    println!("{:?}", ergonomic_list);
+
+    // Compare this against the ListArray built earlier: the value_data, value_offsets and
+    // add_child_data steps are all folded into the single from_iter_primitive call above, which is
+    // why it is the constructor to reach for whenever the data is already available as an iterator.
+
+    // FixedSizeListArray and FixedSizeBinaryArray
+    //
+    // The ListArray built earlier is variable size: each row can hold a different number of
+    // elements, which is exactly why it needs an offsets buffer to mark where every sub-list
+    // starts and ends. When every row is known to have the same length -- coordinate triples,
+    // fixed-width embeddings, RGB pixels -- a FixedSizeListArray drops the offsets buffer entirely.
+    // The constant list length is stored once in DataType::FixedSizeList(field, len), and each
+    // element's boundaries are derived by multiplying the row index by that length.
+    //
+    // The FixedSizeListBuilder takes the child value builder and the fixed list length up front.
+    // Each row appends exactly `len` values to the child builder and then closes the row with
+    // append(true).
+    let values_builder = Int32Builder::new(9);
+    let mut fixed_list_builder = FixedSizeListBuilder::new(values_builder, 3);
+    fixed_list_builder.values().append_slice(&[0, 1, 2]).unwrap();
+    fixed_list_builder.append(true).unwrap();
+    fixed_list_builder.values().append_slice(&[3, 4, 5]).unwrap();
+    fixed_list_builder.append(true).unwrap();
+    fixed_list_builder.values().append_slice(&[6, 7, 8]).unwrap();
+    fixed_list_builder.append(true).unwrap();
+    let fixed_list = fixed_list_builder.finish();
+    println!("{:?}", fixed_list);
+
+    // Printing the child data shows the flattened nine values with no accompanying offsets buffer:
+    // the three rows are carved out of the child purely by the length of 3 recorded in the type.
+    println!("{:?}", fixed_list.values());
+
+    // The same fixed-width idea applies to raw bytes through the FixedSizeBinaryArray, which stores
+    // equal-length byte blobs back to back. Like the fixed-size list it has no offsets buffer; the
+    // byte width is part of the type. try_from_iter infers that width from the first element and
+    // validates that every blob matches it.
+    let blobs = vec![vec![b'f', b'o', b'o'], vec![b'b', b'a', b'r'], vec![b'b', b'a', b'z']];
+    let fixed_binary = FixedSizeBinaryArray::try_from_iter(blobs.into_iter()).unwrap();
+    println!("{:?}", fixed_binary);
+}
+
+// Builds a DictionaryArray<K> from a slice of string values, letting the dictionary builder
+// auto-intern the repeated entries. Every distinct string ends up once in the values array while
+// the returned array keeps only an integer key per appended value. The key type is generic so the
+// caller can pick how wide the keys buffer needs to be (e.g. Int8Type for a handful of distinct
+// values).
+fn build_string_dictionary<K: ArrowDictionaryKeyType>(values: &[&str]) -> DictionaryArray<K> {
+    let keys_builder = PrimitiveBuilder::<K>::new(values.len());
+    let values_builder = StringBuilder::new(values.len());
+    let mut builder = StringDictionaryBuilder::new(keys_builder, values_builder);
+    for value in values {
+        builder.append(value).unwrap();
+    }
+    builder.finish()
 }